@@ -0,0 +1,290 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A vector with a compile-time fixed capacity that stores its elements inline.
+//!
+//! Unlike [`alloc::vec::Vec`] it never allocates and can therefore be used on
+//! bare-metal and enclave targets under `#![no_std]` + `alloc`. Serialization
+//! support is provided through the optional `serde` feature, built without the
+//! serde `std` feature so it remains `no_std`.
+
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+use core::ops::{Index, IndexMut};
+
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+
+/// A vector with a fixed compile-time `CAPACITY` storing its elements inline.
+#[repr(C)]
+pub struct StaticVec<T, const CAPACITY: usize> {
+    len: usize,
+    data: [MaybeUninit<T>; CAPACITY],
+}
+
+impl<T, const CAPACITY: usize> StaticVec<T, CAPACITY> {
+    /// Creates a new empty vector.
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+
+    /// Returns the number of elements currently stored.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the vector cannot store any further elements.
+    pub fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    /// Returns the fixed capacity of the vector.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Appends `value` at the end. Returns false if the vector is already full.
+    pub fn push(&mut self, value: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.data[self.len].write(value);
+        self.len += 1;
+        true
+    }
+
+    /// Inserts `value` at `index`, shifting subsequent elements to the right.
+    /// Returns false if the vector is full or `index` is out of bounds.
+    pub fn insert(&mut self, index: usize, value: T) -> bool {
+        if self.is_full() || index > self.len {
+            return false;
+        }
+
+        for i in (index..self.len).rev() {
+            let element = unsafe { self.data[i].assume_init_read() };
+            self.data[i + 1].write(element);
+        }
+        self.data[index].write(value);
+        self.len += 1;
+        true
+    }
+
+    /// Removes and returns the last element, or `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { self.data[self.len].assume_init_read() })
+    }
+
+    /// Removes and returns the element at `index`, shifting subsequent elements
+    /// to the left, or `None` if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let value = unsafe { self.data[index].assume_init_read() };
+        for i in index..self.len - 1 {
+            let element = unsafe { self.data[i + 1].assume_init_read() };
+            self.data[i].write(element);
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes all elements, dropping them.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if out
+    /// of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Returns the stored elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    /// Returns the stored elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Returns an iterator over the stored elements.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the stored elements.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> StaticVec<T, CAPACITY> {
+    /// Appends a clone of every element of `other`. Returns false if not all
+    /// elements fit.
+    pub fn extend_from_slice(&mut self, other: &[T]) -> bool {
+        for element in other {
+            if !self.push(element.clone()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<T, const CAPACITY: usize> core::ops::Deref for StaticVec<T, CAPACITY> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAPACITY: usize> core::ops::DerefMut for StaticVec<T, CAPACITY> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for StaticVec<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for StaticVec<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        // Only the length needs initializing; the `MaybeUninit` slots stay
+        // uninitialized until an element is written.
+        core::ptr::addr_of_mut!((*ptr).len).write(0);
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for StaticVec<T, CAPACITY> {
+    fn drop(&mut self) {
+        for i in 0..self.len {
+            unsafe { self.data[i].assume_init_drop() };
+        }
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> Clone for StaticVec<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for element in self.as_slice() {
+            new_self.push(element.clone());
+        }
+        new_self
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for StaticVec<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for StaticVec<T, CAPACITY> {}
+
+impl<T: Debug, const CAPACITY: usize> Debug for StaticVec<T, CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice().iter()).finish()
+    }
+}
+
+impl<T, const CAPACITY: usize> Index<usize> for StaticVec<T, CAPACITY> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, const CAPACITY: usize> IndexMut<usize> for StaticVec<T, CAPACITY> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const CAPACITY: usize> serde::Serialize for StaticVec<T, CAPACITY> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for element in self.as_slice() {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const CAPACITY: usize> serde::Deserialize<'de>
+    for StaticVec<T, CAPACITY>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+
+        struct StaticVecVisitor<T, const CAPACITY: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const CAPACITY: usize> serde::de::Visitor<'de>
+            for StaticVecVisitor<T, CAPACITY>
+        {
+            type Value = StaticVec<T, CAPACITY>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "a sequence with at most {CAPACITY} elements")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut vec = StaticVec::new();
+                while let Some(element) = seq.next_element()? {
+                    if !vec.push(element) {
+                        return Err(serde::de::Error::invalid_length(
+                            CAPACITY + 1,
+                            &"a sequence fitting the vector capacity",
+                        ));
+                    }
+                }
+                Ok(vec)
+            }
+        }
+
+        deserializer.deserialize_seq(StaticVecVisitor::<T, CAPACITY>(PhantomData))
+    }
+}
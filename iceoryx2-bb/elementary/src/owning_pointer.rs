@@ -0,0 +1,85 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A pointer that owns a heap-allocated array of `T` and releases it on drop.
+
+extern crate alloc;
+
+use core::alloc::Layout;
+use core::fmt::Debug;
+
+use crate::relocatable_ptr::PointerTrait;
+
+/// A pointer owning a contiguous, heap-allocated region of `capacity` elements
+/// of type `T`.
+#[derive(Debug)]
+#[repr(C)]
+pub struct OwningPointer<T> {
+    ptr: *mut T,
+    capacity: usize,
+}
+
+impl<T> OwningPointer<T> {
+    /// Allocates memory for `capacity` elements. Panics when the allocation
+    /// fails. Use [`OwningPointer::try_new_with_alloc`] to handle allocation
+    /// failure gracefully.
+    pub fn new_with_alloc(capacity: usize) -> Self {
+        match Self::try_new_with_alloc(capacity) {
+            Some(value) => value,
+            None => {
+                panic!("Failed to allocate memory for OwningPointer<{}>.", core::any::type_name::<T>())
+            }
+        }
+    }
+
+    /// Fallible counterpart of [`OwningPointer::new_with_alloc`]. Attempts to
+    /// allocate memory for `capacity` elements and returns `None` on failure
+    /// without aborting, so callers can degrade gracefully. The returned memory
+    /// is uninitialized.
+    pub fn try_new_with_alloc(capacity: usize) -> Option<Self> {
+        if capacity == 0 {
+            return Some(Self {
+                ptr: core::ptr::NonNull::dangling().as_ptr(),
+                capacity,
+            });
+        }
+
+        let layout = Layout::array::<T>(capacity).ok()?;
+        let ptr = unsafe { alloc::alloc::alloc(layout) as *mut T };
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(Self { ptr, capacity })
+    }
+}
+
+impl<T> Drop for OwningPointer<T> {
+    fn drop(&mut self) {
+        if self.capacity != 0 {
+            // `try_new_with_alloc` only succeeds for a valid layout, so the
+            // same layout is valid here.
+            let layout = Layout::array::<T>(self.capacity).unwrap();
+            unsafe { alloc::alloc::dealloc(self.ptr as *mut u8, layout) };
+        }
+    }
+}
+
+impl<T: Debug> PointerTrait<T> for OwningPointer<T> {
+    fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+}
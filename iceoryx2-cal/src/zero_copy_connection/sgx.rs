@@ -0,0 +1,90 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! PAL shims for running a zero-copy connection inside an Intel SGX enclave.
+//!
+//! Inside an enclave the protected address space (EPC) is not shareable with an
+//! untrusted host subscriber. Any memory used to exchange samples must therefore
+//! live in *untrusted* host memory outside the EPC, while the management metadata
+//! of the container stays inside the enclave. This module wraps the enclave's
+//! allocation ABI (`x86_64-fortanix-unknown-sgx` / Teaclave SGX SDK) behind the
+//! [`alloc_untrusted`], [`free_untrusted`] and [`is_outside_enclave`] helpers
+//! used by [`super::used_chunk_list`].
+
+use core::ffi::c_void;
+
+/// Errors that can occur while reserving untrusted host memory for a relocatable
+/// container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UntrustedAllocError {
+    /// The enclave runtime refused to hand out untrusted memory of the requested
+    /// size.
+    OutOfUntrustedMemory,
+    /// The returned region overlaps the enclave range and can therefore not be
+    /// safely shared with the host.
+    RegionInsideEnclave,
+}
+
+extern "C" {
+    /// OCALL reserving `size` bytes of host-resident memory outside the EPC.
+    /// Returns a null pointer on failure.
+    fn iox2_ocall_alloc_untrusted(size: usize, align: usize) -> *mut c_void;
+
+    /// OCALL releasing memory previously returned by
+    /// [`iox2_ocall_alloc_untrusted`].
+    fn iox2_ocall_free_untrusted(ptr: *mut c_void, size: usize, align: usize);
+
+    /// ECALL-side predicate verifying that `[ptr, ptr + size)` lies entirely
+    /// outside the enclave range.
+    fn iox2_sgx_is_outside_enclave(ptr: *const c_void, size: usize) -> bool;
+}
+
+/// Reserves `size` bytes of untrusted host memory and verifies that the region
+/// lives outside the enclave, so that it can be shared with an untrusted host
+/// subscriber.
+///
+/// # Safety
+///
+/// The returned pointer must be released with [`free_untrusted`] using the same
+/// `size` and `align`.
+pub(crate) unsafe fn alloc_untrusted(
+    size: usize,
+    align: usize,
+) -> Result<*mut u8, UntrustedAllocError> {
+    let ptr = iox2_ocall_alloc_untrusted(size, align);
+    if ptr.is_null() {
+        return Err(UntrustedAllocError::OutOfUntrustedMemory);
+    }
+
+    if !is_outside_enclave(ptr as *const u8, size) {
+        iox2_ocall_free_untrusted(ptr, size, align);
+        return Err(UntrustedAllocError::RegionInsideEnclave);
+    }
+
+    Ok(ptr as *mut u8)
+}
+
+/// Releases memory previously obtained from [`alloc_untrusted`].
+///
+/// # Safety
+///
+/// `ptr`, `size` and `align` must match a prior successful [`alloc_untrusted`]
+/// call that has not been freed yet.
+pub(crate) unsafe fn free_untrusted(ptr: *mut u8, size: usize, align: usize) {
+    iox2_ocall_free_untrusted(ptr as *mut c_void, size, align);
+}
+
+/// Returns whether `[ptr, ptr + size)` lies entirely in untrusted host memory.
+#[inline(always)]
+pub(crate) fn is_outside_enclave(ptr: *const u8, size: usize) -> bool {
+    unsafe { iox2_sgx_is_outside_enclave(ptr as *const c_void, size) }
+}
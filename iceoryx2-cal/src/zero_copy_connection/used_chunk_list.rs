@@ -10,12 +10,13 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::{
+use core::{
     alloc::Layout,
     sync::atomic::{AtomicBool, Ordering},
 };
 
 use iceoryx2_bb_elementary::{
+    allocator::AllocationError,
     math::align_to,
     owning_pointer::OwningPointer,
     relocatable_container::RelocatableContainer,
@@ -25,11 +26,16 @@ use iceoryx2_bb_log::{fail, fatal_panic};
 
 use crate::shm_allocator::PointerOffset;
 
+#[cfg(feature = "sgx_enclave")]
+use crate::zero_copy_connection::sgx;
+
 pub type UsedChunkList = details::UsedChunkList<OwningPointer<AtomicBool>>;
 pub type RelocatableUsedChunkList = details::UsedChunkList<RelocatablePointer<AtomicBool>>;
 
 pub mod details {
-    use std::fmt::Debug;
+    use core::fmt::Debug;
+    #[cfg(feature = "sgx_enclave")]
+    use core::ptr::NonNull;
 
     use iceoryx2_bb_elementary::{math::unaligned_mem_size, owning_pointer::OwningPointer};
 
@@ -60,6 +66,30 @@ pub mod details {
                 is_memory_initialized: AtomicBool::new(true),
             }
         }
+
+        /// Fallible variant of [`UsedChunkList::new`]. Instead of panicking
+        /// when the allocation of the data memory fails - as
+        /// [`OwningPointer::new_with_alloc`] does - it returns an
+        /// [`AllocationError`], so that callers embedding iceoryx2 in
+        /// constrained or long-running daemons can degrade gracefully.
+        pub fn try_new(capacity: usize) -> Result<Self, AllocationError> {
+            // Allocate once through the fallible sibling of `new_with_alloc`,
+            // which uses the very same allocator. Only on success are the
+            // `AtomicBool` slots written into that buffer; on failure no memory
+            // is touched and a typed error is returned.
+            let mut data_ptr = OwningPointer::<AtomicBool>::try_new_with_alloc(capacity)
+                .ok_or(AllocationError::OutOfMemory)?;
+
+            for i in 0..capacity {
+                unsafe { data_ptr.as_mut_ptr().add(i).write(AtomicBool::new(false)) };
+            }
+
+            Ok(Self {
+                data_ptr,
+                capacity,
+                is_memory_initialized: AtomicBool::new(true),
+            })
+        }
     }
 
     impl RelocatableContainer for UsedChunkList<RelocatablePointer<AtomicBool>> {
@@ -80,10 +110,27 @@ pub mod details {
                 "Memory already initialized. Initializing it twice may lead to undefined behavior.");
             }
 
-            let memory = fail!(from self, when allocator
-            .allocate(Layout::from_size_align_unchecked(
-                    std::mem::size_of::<AtomicBool>() * self.capacity,
-                    std::mem::align_of::<AtomicBool>())),
+            let layout = Layout::from_size_align_unchecked(
+                core::mem::size_of::<AtomicBool>() * self.capacity,
+                core::mem::align_of::<AtomicBool>(),
+            );
+
+            // Inside an SGX enclave the data region must live in untrusted host
+            // memory so that an untrusted host subscriber can reach the chunks,
+            // while the management metadata (capacity, is_memory_initialized)
+            // stays inside the enclave. The backing SHM allocator therefore hands
+            // out nothing and the region is reserved through the enclave ABI.
+            #[cfg(feature = "sgx_enclave")]
+            let memory = {
+                let _ = allocator;
+                let ptr = fail!(from self, when sgx::alloc_untrusted(layout.size(), layout.align())
+                    .map_err(|_| iceoryx2_bb_elementary::allocator::AllocationError::OutOfMemory),
+                "Failed to initialize since the reservation of untrusted host memory failed.");
+                NonNull::slice_from_raw_parts(NonNull::new_unchecked(ptr), layout.size())
+            };
+
+            #[cfg(not(feature = "sgx_enclave"))]
+            let memory = fail!(from self, when allocator.allocate(layout),
             "Failed to initialize since the allocation of the data memory failed.");
 
             self.data_ptr.init(memory);
@@ -117,6 +164,35 @@ pub mod details {
         }
     }
 
+    #[cfg(feature = "sgx_enclave")]
+    impl UsedChunkList<RelocatablePointer<AtomicBool>> {
+        /// Releases the untrusted host memory reserved by [`RelocatableContainer::init`]
+        /// in SGX mode.
+        ///
+        /// The relocatable container is deliberately non-owning - it has no
+        /// `Drop`, since it may be placed in and torn down with the shm segment
+        /// or bitwise-relocated. The owner of the shm segment must therefore
+        /// call this exactly once during teardown, on the original container
+        /// (not on a relocated copy), after no host subscriber maps the region
+        /// anymore.
+        ///
+        /// # Safety
+        ///
+        /// Must be called at most once, on the container that reserved the
+        /// memory, while no other party still accesses the data region.
+        pub unsafe fn release_untrusted_memory(&self) {
+            if !self.is_memory_initialized.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let size = core::mem::size_of::<AtomicBool>() * self.capacity;
+            let ptr = self.data_ptr.as_ptr() as *mut u8;
+            if sgx::is_outside_enclave(ptr, size) {
+                sgx::free_untrusted(ptr, size, core::mem::align_of::<AtomicBool>());
+            }
+        }
+    }
+
     impl<PointerType: PointerTrait<AtomicBool> + Debug> UsedChunkList<PointerType> {
         pub const fn const_memory_size(capacity: usize) -> usize {
             unaligned_mem_size::<AtomicBool>(capacity)
@@ -135,13 +211,32 @@ pub mod details {
             );
         }
 
-        fn set(&self, idx: usize, value: bool) -> bool {
-            self.verify_init("set");
+        // When the data region lives in untrusted host memory (SGX enclave mode)
+        // the index may have been corrupted by the host, so the bounds check is
+        // promoted from a `debug_assert!` to a real runtime check.
+        #[cfg(not(feature = "sgx_enclave"))]
+        #[inline(always)]
+        fn verify_index(&self, idx: usize, source: &str) {
             debug_assert!(
                 idx < self.capacity,
-                "This should never happen. Out of bounds access with index {}.",
+                "This should never happen. Out of bounds access in \"{}\" with index {}.",
+                source,
                 idx
             );
+        }
+
+        #[cfg(feature = "sgx_enclave")]
+        #[inline(always)]
+        fn verify_index(&self, idx: usize, source: &str) {
+            if idx >= self.capacity {
+                fatal_panic!(from self,
+                    "Out of bounds access in \"{}\" with untrusted index {}.", source, idx);
+            }
+        }
+
+        fn set(&self, idx: usize, value: bool) -> bool {
+            self.verify_init("set");
+            self.verify_index(idx, "set");
 
             unsafe { (*self.data_ptr.as_ptr().add(idx)).swap(value, Ordering::Relaxed) }
         }
@@ -179,7 +274,7 @@ impl<const CAPACITY: usize> Default for FixedSizeUsedChunkList<CAPACITY> {
             list: unsafe {
                 RelocatableUsedChunkList::new(
                     CAPACITY,
-                    align_to::<AtomicBool>(std::mem::size_of::<RelocatableUsedChunkList>()) as _,
+                    align_to::<AtomicBool>(core::mem::size_of::<RelocatableUsedChunkList>()) as _,
                 )
             },
             data: core::array::from_fn(|_| AtomicBool::new(false)),
@@ -0,0 +1,47 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod used_chunk_list {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::zero_copy_connection::used_chunk_list::UsedChunkList;
+
+    const SUT_CAPACITY: usize = 128;
+
+    #[test]
+    fn try_new_creates_empty_list() {
+        let sut = UsedChunkList::try_new(SUT_CAPACITY).unwrap();
+
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+
+        let mut remaining = 0;
+        sut.remove_all(|_| remaining += 1);
+        assert_that!(remaining, eq 0);
+    }
+
+    #[test]
+    fn try_new_list_inserts_and_removes() {
+        let sut = UsedChunkList::try_new(SUT_CAPACITY).unwrap();
+
+        assert_that!(sut.insert(3), eq true);
+        assert_that!(sut.insert(3), eq false);
+        assert_that!(sut.remove(3), eq true);
+        assert_that!(sut.remove(3), eq false);
+    }
+
+    #[test]
+    fn try_new_returns_error_on_allocation_failure() {
+        let sut = UsedChunkList::try_new(usize::MAX);
+
+        assert_that!(sut.is_err(), eq true);
+    }
+}
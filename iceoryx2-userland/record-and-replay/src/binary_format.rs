@@ -0,0 +1,381 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compact, seekable binary recording format for the record & replay crate.
+//!
+//! The original on-disk format is whitespace-separated hex text, which is slow
+//! to parse and impossible to seek. This module defines an alternative, compact
+//! layout that new recordings default to:
+//!
+//! ```text
+//! [ header ][ record ][ record ] ... [ seek index ][ index offset : u64 ]
+//! ```
+//!
+//! The header captures the [`ServiceTypes`] of the recording, each record is
+//! length-prefixed and carries a monotonic timestamp, and the trailing seek
+//! index maps timestamps to file offsets so that a replayer can jump to an
+//! arbitrary point without scanning the whole file. The hex text format remains
+//! selectable for human-readable debugging.
+
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+use crate::recorder::ServiceTypes;
+
+/// Magic bytes identifying a binary recording (`IOX2REC\0`).
+pub const MAGIC: [u8; 8] = *b"IOX2REC\0";
+
+/// Version of the binary recording layout. Bumped on any incompatible change.
+pub const VERSION: u16 = 1;
+
+/// Errors that can occur while reading the header of an existing binary
+/// recording. Surfaced to the caller as a `RecorderCreateError` variant for
+/// header/version mismatch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderReadError {
+    /// The file does not start with the expected [`MAGIC`].
+    InvalidMagic,
+    /// The file was written by an incompatible format version.
+    UnsupportedVersion { found: u16, expected: u16 },
+    /// The header is shorter than expected.
+    Truncated,
+    /// A type descriptor carries an unknown [`TypeVariant`] discriminant.
+    CorruptVariant,
+}
+
+/// A type descriptor as stored in the header: the [`TypeVariant`] together with
+/// the `size` and `alignment` that were recorded. Returned by [`read_header`]
+/// so a recording can be validated against the service it is replayed into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordedTypeDetail {
+    pub variant: TypeVariant,
+    pub size: usize,
+    pub alignment: usize,
+}
+
+impl RecordedTypeDetail {
+    /// Whether this descriptor matches the given [`TypeDetail`].
+    pub fn matches(&self, detail: &TypeDetail) -> bool {
+        self.variant == detail.variant
+            && self.size == detail.size
+            && self.alignment == detail.alignment
+    }
+}
+
+fn variant_to_u8(variant: &TypeVariant) -> u8 {
+    match variant {
+        TypeVariant::FixedSize => 0,
+        TypeVariant::Dynamic => 1,
+    }
+}
+
+fn u8_to_variant(value: u8) -> Option<TypeVariant> {
+    match value {
+        0 => Some(TypeVariant::FixedSize),
+        1 => Some(TypeVariant::Dynamic),
+        _ => None,
+    }
+}
+
+fn put_u64(out: &mut [u8], offset: usize, value: u64) {
+    out[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
+
+fn get_u64(input: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&input[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Fixed-size, per-type descriptor stored in the header: `variant` followed by
+/// `size` and `alignment`.
+const TYPE_DETAIL_LEN: usize = 1 + 8 + 8;
+
+/// Number of bytes the header occupies: magic, version, and the three type
+/// descriptors (payload, user_header, system_header).
+pub const HEADER_LEN: usize = MAGIC.len() + 2 + 3 * TYPE_DETAIL_LEN;
+
+fn write_type_detail(out: &mut [u8], offset: usize, detail: &TypeDetail) {
+    out[offset] = variant_to_u8(&detail.variant);
+    put_u64(out, offset + 1, detail.size as u64);
+    put_u64(out, offset + 9, detail.alignment as u64);
+}
+
+fn read_type_detail(input: &[u8], offset: usize) -> Result<RecordedTypeDetail, HeaderReadError> {
+    let variant = u8_to_variant(input[offset]).ok_or(HeaderReadError::CorruptVariant)?;
+    Ok(RecordedTypeDetail {
+        variant,
+        size: get_u64(input, offset + 1) as usize,
+        alignment: get_u64(input, offset + 9) as usize,
+    })
+}
+
+/// Serializes the recording header into `out`, which is exactly
+/// [`HEADER_LEN`] bytes long.
+pub fn write_header(types: &ServiceTypes, out: &mut [u8; HEADER_LEN]) {
+    let mut offset = 0;
+    out[offset..offset + MAGIC.len()].copy_from_slice(&MAGIC);
+    offset += MAGIC.len();
+
+    out[offset..offset + 2].copy_from_slice(&VERSION.to_le_bytes());
+    offset += 2;
+
+    write_type_detail(out, offset, &types.payload);
+    offset += TYPE_DETAIL_LEN;
+    write_type_detail(out, offset, &types.user_header);
+    offset += TYPE_DETAIL_LEN;
+    write_type_detail(out, offset, &types.system_header);
+}
+
+/// Validates magic and version of an existing binary recording header and
+/// returns the full `(payload, user_header, system_header)` type descriptors -
+/// including `size` and `alignment` - so the recording can be validated against
+/// the service it is replayed into.
+pub fn read_header(input: &[u8]) -> Result<[RecordedTypeDetail; 3], HeaderReadError> {
+    if input.len() < HEADER_LEN {
+        return Err(HeaderReadError::Truncated);
+    }
+
+    if input[..MAGIC.len()] != MAGIC {
+        return Err(HeaderReadError::InvalidMagic);
+    }
+
+    let mut offset = MAGIC.len();
+    let version = u16::from_le_bytes([input[offset], input[offset + 1]]);
+    if version != VERSION {
+        return Err(HeaderReadError::UnsupportedVersion {
+            found: version,
+            expected: VERSION,
+        });
+    }
+    offset += 2;
+
+    let payload = read_type_detail(input, offset)?;
+    offset += TYPE_DETAIL_LEN;
+    let user_header = read_type_detail(input, offset)?;
+    offset += TYPE_DETAIL_LEN;
+    let system_header = read_type_detail(input, offset)?;
+
+    Ok([payload, user_header, system_header])
+}
+
+/// Header of a single length-prefixed record: a monotonic `timestamp` followed
+/// by the payload `length`. Immediately followed on disk by `length` raw bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordFrame {
+    pub timestamp: u64,
+    pub length: u64,
+}
+
+impl RecordFrame {
+    /// Size of the serialized frame prefix (timestamp + length).
+    pub const LEN: usize = 16;
+
+    pub fn write(&self, out: &mut [u8; Self::LEN]) {
+        put_u64(out, 0, self.timestamp);
+        put_u64(out, 8, self.length);
+    }
+
+    pub fn read(input: &[u8]) -> Option<Self> {
+        if input.len() < Self::LEN {
+            return None;
+        }
+        Some(Self {
+            timestamp: get_u64(input, 0),
+            length: get_u64(input, 8),
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod index {
+    extern crate alloc;
+
+    use super::{get_u64, put_u64};
+    use alloc::vec::Vec;
+
+    /// Trailing seek index mapping record timestamps to their byte offset in the
+    /// file, so a replayer can jump to an arbitrary point without scanning.
+    #[derive(Debug, Default, Clone)]
+    pub struct SeekIndex {
+        entries: Vec<(u64, u64)>,
+    }
+
+    impl SeekIndex {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Records that the record with `timestamp` starts at `offset`.
+        /// Timestamps are expected to be inserted in monotonic order.
+        pub fn push(&mut self, timestamp: u64, offset: u64) {
+            self.entries.push((timestamp, offset));
+        }
+
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.entries.is_empty()
+        }
+
+        /// Returns the file offset of the last record whose timestamp is less
+        /// than or equal to `timestamp`, i.e. the entry point for a seek.
+        pub fn seek(&self, timestamp: u64) -> Option<u64> {
+            match self.entries.partition_point(|(t, _)| *t <= timestamp) {
+                0 => None,
+                idx => Some(self.entries[idx - 1].1),
+            }
+        }
+
+        /// Serializes the index as a sequence of `(timestamp, offset)` pairs.
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut out = alloc::vec![0u8; self.entries.len() * 16];
+            for (i, (timestamp, offset)) in self.entries.iter().enumerate() {
+                put_u64(&mut out, i * 16, *timestamp);
+                put_u64(&mut out, i * 16 + 8, *offset);
+            }
+            out
+        }
+
+        /// Reconstructs an index from its serialized representation.
+        pub fn from_bytes(input: &[u8]) -> Self {
+            let mut entries = Vec::with_capacity(input.len() / 16);
+            let mut offset = 0;
+            while offset + 16 <= input.len() {
+                entries.push((get_u64(input, offset), get_u64(input, offset + 8)));
+                offset += 16;
+            }
+            Self { entries }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use index::SeekIndex;
+
+#[cfg(feature = "alloc")]
+mod recording {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    use crate::recorder::ServiceTypes;
+
+    /// Assembles a complete binary recording in memory as
+    /// `[header][records][seek index][index offset : u64]`.
+    ///
+    /// Records must be appended with monotonically increasing timestamps. The
+    /// produced buffer can be written directly to a file, a network buffer or a
+    /// memory-mapped region.
+    pub struct BinaryRecordingWriter {
+        buffer: Vec<u8>,
+        index: SeekIndex,
+    }
+
+    impl BinaryRecordingWriter {
+        /// Starts a new recording, writing the header for `types`.
+        pub fn new(types: &ServiceTypes) -> Self {
+            let mut header = [0u8; HEADER_LEN];
+            write_header(types, &mut header);
+
+            let mut buffer = Vec::new();
+            buffer.extend_from_slice(&header);
+
+            Self {
+                buffer,
+                index: SeekIndex::new(),
+            }
+        }
+
+        /// Appends a length-prefixed, timestamped record and indexes its offset.
+        pub fn append(&mut self, timestamp: u64, payload: &[u8]) {
+            self.index.push(timestamp, self.buffer.len() as u64);
+
+            let frame = RecordFrame {
+                timestamp,
+                length: payload.len() as u64,
+            };
+            let mut frame_bytes = [0u8; RecordFrame::LEN];
+            frame.write(&mut frame_bytes);
+            self.buffer.extend_from_slice(&frame_bytes);
+            self.buffer.extend_from_slice(payload);
+        }
+
+        /// Finalizes the recording by appending the seek index and a trailing
+        /// `u64` with the index offset, and returns the assembled buffer.
+        pub fn finish(mut self) -> Vec<u8> {
+            let index_offset = self.buffer.len() as u64;
+            self.buffer.extend_from_slice(&self.index.to_bytes());
+
+            let mut offset_bytes = [0u8; 8];
+            put_u64(&mut offset_bytes, 0, index_offset);
+            self.buffer.extend_from_slice(&offset_bytes);
+
+            self.buffer
+        }
+    }
+
+    /// Reads a complete binary recording assembled by [`BinaryRecordingWriter`],
+    /// using the trailing seek index to jump to an arbitrary timestamp without
+    /// scanning every record.
+    pub struct BinaryRecordingReader<'a> {
+        data: &'a [u8],
+        types: [RecordedTypeDetail; 3],
+        index: SeekIndex,
+    }
+
+    impl<'a> BinaryRecordingReader<'a> {
+        /// Opens a recording, validating its header and loading the seek index.
+        pub fn open(data: &'a [u8]) -> Result<Self, HeaderReadError> {
+            let types = read_header(data)?;
+
+            if data.len() < HEADER_LEN + 8 {
+                return Err(HeaderReadError::Truncated);
+            }
+
+            let index_offset = get_u64(data, data.len() - 8) as usize;
+            if index_offset > data.len() - 8 {
+                return Err(HeaderReadError::Truncated);
+            }
+
+            let index = SeekIndex::from_bytes(&data[index_offset..data.len() - 8]);
+
+            Ok(Self { data, types, index })
+        }
+
+        /// The recorded type descriptors.
+        pub fn types(&self) -> &[RecordedTypeDetail; 3] {
+            &self.types
+        }
+
+        /// Reads the record stored at `offset`, returning its frame and payload.
+        pub fn record_at(&self, offset: usize) -> Option<(RecordFrame, &'a [u8])> {
+            let frame = RecordFrame::read(self.data.get(offset..)?)?;
+            let start = offset + RecordFrame::LEN;
+            let end = start + frame.length as usize;
+            let payload = self.data.get(start..end)?;
+            Some((frame, payload))
+        }
+
+        /// Jumps to the last record whose timestamp is `<= timestamp` and
+        /// returns its frame and payload, or `None` if no such record exists.
+        pub fn seek(&self, timestamp: u64) -> Option<(RecordFrame, &'a [u8])> {
+            let offset = self.index.seek(timestamp)?;
+            self.record_at(offset as usize)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use recording::{BinaryRecordingReader, BinaryRecordingWriter};
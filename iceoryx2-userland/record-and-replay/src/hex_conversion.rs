@@ -10,18 +10,75 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
 use iceoryx2_bb_log::debug;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HexToBytesConversionError {
     InvalidHexCode,
+    BufferTooSmall,
+}
+
+/// Converts a single whitespace-separated hex string into the provided output
+/// slice and returns the number of bytes written. This is the no-alloc variant
+/// of [`hex_string_to_bytes`] for bare-metal and enclave targets.
+pub fn hex_string_to_slice(
+    hex_string: &str,
+    buffer: &mut [u8],
+) -> Result<usize, HexToBytesConversionError> {
+    let mut len = 0;
+    for hex in hex_string.split_ascii_whitespace() {
+        let byte = u8::from_str_radix(hex, 16).map_err(|e| {
+            debug!(from "hex_string_to_slice()",
+                    "Unable convert \"{hex}\" to hex-code ({e:?}).");
+            HexToBytesConversionError::InvalidHexCode
+        })?;
+
+        if len >= buffer.len() {
+            return Err(HexToBytesConversionError::BufferTooSmall);
+        }
+        buffer[len] = byte;
+        len += 1;
+    }
+
+    Ok(len)
+}
+
+/// Writes the two-hex-digit-plus-separator representation of `raw_data` into the
+/// provided output slice and returns the number of bytes written. This is the
+/// no-alloc variant of [`bytes_to_hex_string`].
+pub fn bytes_to_hex_slice(
+    raw_data: &[u8],
+    buffer: &mut [u8],
+) -> Result<usize, HexToBytesConversionError> {
+    const CHARS: &[u8; 16] = b"0123456789abcdef";
+
+    if buffer.len() < 3 * raw_data.len() {
+        return Err(HexToBytesConversionError::BufferTooSmall);
+    }
+
+    let mut len = 0;
+    for byte in raw_data {
+        buffer[len] = CHARS[(byte >> 4) as usize];
+        buffer[len + 1] = CHARS[(byte & 0x0f) as usize];
+        buffer[len + 2] = b' ';
+        len += 3;
+    }
+
+    Ok(len)
 }
 
+#[cfg(feature = "alloc")]
 pub fn hex_string_to_bytes(hex_string: &str) -> Result<Vec<u8>, HexToBytesConversionError> {
     hex_string
         .split_ascii_whitespace()
         .map(|hex| {
-            u8::from_str_radix(&hex, 16).map_err(|e| {
+            u8::from_str_radix(hex, 16).map_err(|e| {
                 debug!(from "hex_string_to_raw_data()",
                         "Unable convert \"{hex}\" to hex-code ({e:?}).");
                 HexToBytesConversionError::InvalidHexCode
@@ -30,8 +87,9 @@ pub fn hex_string_to_bytes(hex_string: &str) -> Result<Vec<u8>, HexToBytesConver
         .collect::<Result<Vec<u8>, HexToBytesConversionError>>()
 }
 
+#[cfg(feature = "alloc")]
 pub fn bytes_to_hex_string(raw_data: &[u8]) -> String {
-    use std::fmt::Write;
+    use core::fmt::Write;
 
     let mut ret_val = String::with_capacity(3 * raw_data.len());
     for byte in raw_data {
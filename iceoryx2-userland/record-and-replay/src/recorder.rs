@@ -0,0 +1,205 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Creates and opens recordings of a service's samples.
+//!
+//! New recordings default to the compact, seekable [binary format](crate::binary_format)
+//! for throughput; the human-readable hex text format stays selectable for
+//! debugging.
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+
+use crate::binary_format::{
+    BinaryRecordingReader, BinaryRecordingWriter, HeaderReadError, RecordFrame,
+};
+use crate::hex_conversion::bytes_to_hex_string;
+
+/// The payload, user-header and system-header types of the recorded service.
+#[derive(Debug, Clone)]
+pub struct ServiceTypes {
+    pub payload: TypeDetail,
+    pub user_header: TypeDetail,
+    pub system_header: TypeDetail,
+}
+
+/// Selects the on-disk recording format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingFormat {
+    /// Human-readable whitespace-separated hex text.
+    Hex,
+    /// Compact, seekable binary format (the default).
+    Binary,
+}
+
+impl Default for RecordingFormat {
+    fn default() -> Self {
+        RecordingFormat::Binary
+    }
+}
+
+/// Failures that can occur while creating or opening a recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecorderCreateError {
+    /// A file with the requested name already exists.
+    FileAlreadyExists,
+    /// The recording file could not be created or opened.
+    UnableToCreateFile,
+    /// An existing binary recording was written by an incompatible format
+    /// version.
+    HeaderVersionMismatch,
+    /// An existing binary recording has a corrupt or truncated header.
+    CorruptHeader,
+    /// The recorded types do not match the service the recording is opened for.
+    TypeMismatch,
+}
+
+impl From<HeaderReadError> for RecorderCreateError {
+    fn from(value: HeaderReadError) -> Self {
+        match value {
+            HeaderReadError::UnsupportedVersion { .. } => RecorderCreateError::HeaderVersionMismatch,
+            HeaderReadError::InvalidMagic
+            | HeaderReadError::Truncated
+            | HeaderReadError::CorruptVariant => RecorderCreateError::CorruptHeader,
+        }
+    }
+}
+
+/// Builder for a [`Recorder`].
+pub struct RecorderBuilder<'a> {
+    types: &'a ServiceTypes,
+    format: RecordingFormat,
+}
+
+impl<'a> RecorderBuilder<'a> {
+    pub fn new(types: &'a ServiceTypes) -> Self {
+        Self {
+            types,
+            format: RecordingFormat::default(),
+        }
+    }
+
+    /// Overrides the recording format. Defaults to [`RecordingFormat::Binary`].
+    pub fn format(mut self, format: RecordingFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Creates a new recording at `file_path`, failing if it already exists.
+    pub fn create<P: AsRef<Path>>(self, file_path: P) -> Result<Recorder, RecorderCreateError> {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&file_path)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => RecorderCreateError::FileAlreadyExists,
+                _ => RecorderCreateError::UnableToCreateFile,
+            })?;
+
+        let binary = match self.format {
+            RecordingFormat::Binary => Some(BinaryRecordingWriter::new(self.types)),
+            RecordingFormat::Hex => None,
+        };
+
+        Ok(Recorder {
+            file,
+            format: self.format,
+            binary,
+        })
+    }
+
+    /// Opens an existing recording, validating its header against `types`, and
+    /// returns a [`Replayer`] for seeking and reading the recorded samples.
+    pub fn open<P: AsRef<Path>>(self, file_path: P) -> Result<Replayer, RecorderCreateError> {
+        let mut file =
+            std::fs::File::open(&file_path).map_err(|_| RecorderCreateError::UnableToCreateFile)?;
+
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)
+            .map_err(|_| RecorderCreateError::CorruptHeader)?;
+
+        // Validate the header - including the recorded types - by opening a
+        // reader over the loaded buffer.
+        let reader = BinaryRecordingReader::open(&data)?;
+        let expected = [
+            &self.types.payload,
+            &self.types.user_header,
+            &self.types.system_header,
+        ];
+        for (recorded, expected) in reader.types().iter().zip(expected) {
+            if !recorded.matches(expected) {
+                return Err(RecorderCreateError::TypeMismatch);
+            }
+        }
+
+        Ok(Replayer { data })
+    }
+}
+
+/// Writes samples to a recording in the selected [`RecordingFormat`].
+///
+/// The on-disk binary layout is produced entirely by
+/// [`BinaryRecordingWriter`], so the format has a single source of truth.
+pub struct Recorder {
+    file: std::fs::File,
+    format: RecordingFormat,
+    binary: Option<BinaryRecordingWriter>,
+}
+
+impl Recorder {
+    /// Appends a length-prefixed, timestamped record in the binary format, or a
+    /// line of hex in the text format.
+    pub fn record(&mut self, timestamp: u64, payload: &[u8]) -> std::io::Result<()> {
+        match &mut self.binary {
+            Some(writer) => writer.append(timestamp, payload),
+            None => {
+                self.file.write_all(bytes_to_hex_string(payload).as_bytes())?;
+                self.file.write_all(b"\n")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Flushes the recording to disk. For the binary format this writes the
+    /// assembled buffer (header, records, seek index and index offset) in one
+    /// go; the hex format is already written incrementally.
+    pub fn finish(mut self) -> std::io::Result<()> {
+        if let Some(writer) = self.binary.take() {
+            self.file.write_all(&writer.finish())?;
+        }
+        self.file.flush()
+    }
+}
+
+/// Replays a binary recording opened via [`RecorderBuilder::open`], seeking to
+/// arbitrary timestamps via the trailing seek index.
+pub struct Replayer {
+    data: Vec<u8>,
+}
+
+impl Replayer {
+    /// Returns a reader over the recording. Re-parses the validated buffer; the
+    /// header was already checked by [`RecorderBuilder::open`].
+    pub fn reader(&self) -> BinaryRecordingReader<'_> {
+        BinaryRecordingReader::open(&self.data)
+            .expect("recording was validated when it was opened")
+    }
+
+    /// Seeks to the last record with a timestamp `<= timestamp` and returns its
+    /// frame and payload, or `None` if no such record exists.
+    pub fn seek(&self, timestamp: u64) -> Option<(RecordFrame, &[u8])> {
+        self.reader().seek(timestamp)
+    }
+}
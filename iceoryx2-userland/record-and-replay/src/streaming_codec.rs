@@ -0,0 +1,243 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Streaming reader/writer for records of the record & replay format.
+//!
+//! Instead of materializing a whole record as a hex `String`/`Vec` up front,
+//! the codec encodes and decodes incrementally against a caller-provided
+//! cursor. This lets a recording be produced directly into a network send
+//! buffer or a memory-mapped region and replayed from one in chunks. The traits
+//! are a minimal in-crate equivalent of the `bytes` crate's `Buf`/`BufMut`, so
+//! that no external dependency is pulled into the no_std core.
+
+use crate::hex_conversion::HexToBytesConversionError;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// A cursor that bytes can be written into incrementally.
+pub trait BufMut {
+    /// Number of bytes that can still be written.
+    fn remaining_mut(&self) -> usize;
+
+    /// Writes a single byte. Returns `false` when no space is left.
+    fn put_u8(&mut self, value: u8) -> bool;
+}
+
+/// A cursor that bytes can be read from incrementally.
+pub trait Buf {
+    /// Number of bytes that can still be read.
+    fn remaining(&self) -> usize;
+
+    /// Reads a single byte, advancing the cursor. Returns `None` when drained.
+    fn get_u8(&mut self) -> Option<u8>;
+}
+
+/// A [`BufMut`]/[`Buf`] backed by a borrowed slice and a write/read position.
+pub struct SliceCursor<'a> {
+    slice: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> SliceCursor<'a> {
+    pub fn new(slice: &'a mut [u8]) -> Self {
+        Self { slice, position: 0 }
+    }
+
+    /// Number of bytes written/read so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl BufMut for SliceCursor<'_> {
+    fn remaining_mut(&self) -> usize {
+        self.slice.len() - self.position
+    }
+
+    fn put_u8(&mut self, value: u8) -> bool {
+        if self.position >= self.slice.len() {
+            return false;
+        }
+        self.slice[self.position] = value;
+        self.position += 1;
+        true
+    }
+}
+
+impl Buf for SliceCursor<'_> {
+    fn remaining(&self) -> usize {
+        self.slice.len() - self.position
+    }
+
+    fn get_u8(&mut self) -> Option<u8> {
+        if self.position >= self.slice.len() {
+            return None;
+        }
+        let value = self.slice[self.position];
+        self.position += 1;
+        Some(value)
+    }
+}
+
+/// Outcome of an incremental encode/decode step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamStatus {
+    /// The whole record has been processed.
+    Complete,
+    /// The cursor ran out of space/input mid-record. Call again with a fresh
+    /// cursor to resume where this call stopped.
+    Incomplete,
+}
+
+/// Incrementally encodes a record as whitespace-separated hex (two hex digits
+/// plus a separator per byte) into successive [`BufMut`] cursors.
+///
+/// The writer keeps track of the byte and sub-character position, so a record
+/// whose output buffer fills mid-way resumes exactly where it left off on the
+/// next call.
+pub struct HexRecordWriter<'a> {
+    record: &'a [u8],
+    byte_index: usize,
+    // 0 => high nibble, 1 => low nibble, 2 => separator
+    sub_index: u8,
+}
+
+impl<'a> HexRecordWriter<'a> {
+    pub fn new(record: &'a [u8]) -> Self {
+        Self {
+            record,
+            byte_index: 0,
+            sub_index: 0,
+        }
+    }
+
+    /// Writes as much of the record as fits into `buffer`. Returns
+    /// [`StreamStatus::Incomplete`] when the buffer filled before the record
+    /// was fully written; the next call resumes at the same byte/digit.
+    pub fn write_into<B: BufMut>(&mut self, buffer: &mut B) -> StreamStatus {
+        while self.byte_index < self.record.len() {
+            let byte = self.record[self.byte_index];
+            let next = match self.sub_index {
+                0 => HEX_CHARS[(byte >> 4) as usize],
+                1 => HEX_CHARS[(byte & 0x0f) as usize],
+                _ => b' ',
+            };
+
+            if !buffer.put_u8(next) {
+                return StreamStatus::Incomplete;
+            }
+
+            if self.sub_index == 2 {
+                self.sub_index = 0;
+                self.byte_index += 1;
+            } else {
+                self.sub_index += 1;
+            }
+        }
+
+        StreamStatus::Complete
+    }
+
+    /// Whether the whole record has been written.
+    pub fn is_complete(&self) -> bool {
+        self.byte_index >= self.record.len()
+    }
+}
+
+/// Incrementally decodes whitespace-separated hex from successive [`Buf`]
+/// cursors, pushing each decoded byte through a sink callback.
+///
+/// Bytes are decoded per whitespace-delimited token, like
+/// [`crate::hex_conversion::hex_string_to_bytes`], so a single-digit token such
+/// as `"a b"` decodes to two bytes instead of being merged across the
+/// separator. A token must consist of one or two hex digits; longer tokens or
+/// any other character are rejected with
+/// [`HexToBytesConversionError::InvalidHexCode`]. A token that is split across
+/// two cursors is carried over via the
+/// internal `token` buffer; call [`HexRecordReader::finish`] once the stream
+/// ends to flush a trailing token that is not whitespace-terminated.
+pub struct HexRecordReader {
+    // Hex digits of the token currently being read.
+    token: [u8; 2],
+    token_len: u8,
+}
+
+impl Default for HexRecordReader {
+    fn default() -> Self {
+        Self {
+            token: [0; 2],
+            token_len: 0,
+        }
+    }
+}
+
+impl HexRecordReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn flush_token<F: FnMut(u8)>(
+        &mut self,
+        sink: &mut F,
+    ) -> Result<(), HexToBytesConversionError> {
+        if self.token_len == 0 {
+            return Ok(());
+        }
+
+        let token = core::str::from_utf8(&self.token[..self.token_len as usize])
+            .map_err(|_| HexToBytesConversionError::InvalidHexCode)?;
+        let byte = u8::from_str_radix(token, 16)
+            .map_err(|_| HexToBytesConversionError::InvalidHexCode)?;
+        sink(byte);
+        self.token_len = 0;
+        Ok(())
+    }
+
+    /// Reads every byte currently available in `buffer`, invoking `sink` for
+    /// each fully decoded token. A token split across two cursors resumes via
+    /// the internal `token` state.
+    pub fn read_from<B: Buf, F: FnMut(u8)>(
+        &mut self,
+        buffer: &mut B,
+        mut sink: F,
+    ) -> Result<StreamStatus, HexToBytesConversionError> {
+        while let Some(c) = buffer.get_u8() {
+            if c.is_ascii_whitespace() {
+                self.flush_token(&mut sink)?;
+                continue;
+            }
+
+            if !c.is_ascii_hexdigit() || self.token_len as usize >= self.token.len() {
+                return Err(HexToBytesConversionError::InvalidHexCode);
+            }
+
+            self.token[self.token_len as usize] = c;
+            self.token_len += 1;
+        }
+
+        if self.token_len == 0 {
+            Ok(StreamStatus::Complete)
+        } else {
+            Ok(StreamStatus::Incomplete)
+        }
+    }
+
+    /// Flushes a trailing token that was not terminated by whitespace, e.g. the
+    /// last token of a human-edited recording. Must be called once the input is
+    /// fully drained.
+    pub fn finish<F: FnMut(u8)>(
+        &mut self,
+        mut sink: F,
+    ) -> Result<(), HexToBytesConversionError> {
+        self.flush_token(&mut sink)
+    }
+}
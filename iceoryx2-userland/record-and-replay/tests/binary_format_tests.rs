@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod binary_format {
+    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2_pal_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::binary_format::{
+        read_header, write_header, BinaryRecordingReader, BinaryRecordingWriter, HeaderReadError,
+        SeekIndex, HEADER_LEN, MAGIC,
+    };
+    use iceoryx2_userland_record_and_replay::recorder::ServiceTypes;
+
+    fn test_types() -> ServiceTypes {
+        ServiceTypes {
+            payload: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+            user_header: TypeDetail::new::<u32>(TypeVariant::FixedSize),
+            system_header: TypeDetail::new::<u8>(TypeVariant::Dynamic),
+        }
+    }
+
+    #[test]
+    fn header_round_trip_is_lossless() {
+        let types = test_types();
+        let mut header = [0u8; HEADER_LEN];
+        write_header(&types, &mut header);
+
+        let recorded = read_header(&header).unwrap();
+
+        assert_that!(recorded[0].matches(&types.payload), eq true);
+        assert_that!(recorded[1].matches(&types.user_header), eq true);
+        assert_that!(recorded[2].matches(&types.system_header), eq true);
+    }
+
+    #[test]
+    fn invalid_magic_is_detected() {
+        let mut header = [0u8; HEADER_LEN];
+        write_header(&test_types(), &mut header);
+        header[0] = !MAGIC[0];
+
+        assert_that!(read_header(&header).err(), eq Some(HeaderReadError::InvalidMagic));
+    }
+
+    #[test]
+    fn version_mismatch_is_detected() {
+        let mut header = [0u8; HEADER_LEN];
+        write_header(&test_types(), &mut header);
+        // Bump the version field (directly after the magic).
+        header[MAGIC.len()] = header[MAGIC.len()].wrapping_add(7);
+
+        assert_that!(read_header(&header).is_err(), eq true);
+    }
+
+    #[test]
+    fn corrupt_variant_byte_is_distinct_error() {
+        let mut header = [0u8; HEADER_LEN];
+        write_header(&test_types(), &mut header);
+        // The first type descriptor's variant byte follows magic + version.
+        header[MAGIC.len() + 2] = 0xff;
+
+        assert_that!(read_header(&header).err(), eq Some(HeaderReadError::CorruptVariant));
+    }
+
+    #[test]
+    fn seek_index_returns_floor_offset() {
+        let mut index = SeekIndex::new();
+        index.push(10, 100);
+        index.push(20, 200);
+        index.push(30, 300);
+
+        assert_that!(index.seek(5), eq None);
+        assert_that!(index.seek(10), eq Some(100));
+        assert_that!(index.seek(25), eq Some(200));
+        assert_that!(index.seek(999), eq Some(300));
+    }
+
+    #[test]
+    fn seek_index_serialization_round_trips() {
+        let mut index = SeekIndex::new();
+        index.push(1, 42);
+        index.push(2, 84);
+
+        let restored = SeekIndex::from_bytes(&index.to_bytes());
+
+        assert_that!(restored.len(), eq 2);
+        assert_that!(restored.seek(2), eq Some(84));
+    }
+
+    #[test]
+    fn recording_can_be_seeked_without_scanning() {
+        let types = test_types();
+        let mut writer = BinaryRecordingWriter::new(&types);
+        writer.append(100, &[1, 2, 3]);
+        writer.append(200, &[4, 5]);
+        writer.append(300, &[6, 7, 8, 9]);
+        let buffer = writer.finish();
+
+        let reader = BinaryRecordingReader::open(&buffer).unwrap();
+        assert_that!(reader.types()[0].matches(&types.payload), eq true);
+
+        let (frame, payload) = reader.seek(250).unwrap();
+        assert_that!(frame.timestamp, eq 200);
+        assert_that!(payload, eq vec![4u8, 5].as_slice());
+
+        assert_that!(reader.seek(50).is_none(), eq true);
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod hex_conversion {
+    use iceoryx2_pal_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::hex_conversion::{
+        bytes_to_hex_slice, hex_string_to_slice, HexToBytesConversionError,
+    };
+
+    #[test]
+    fn bytes_to_hex_slice_writes_two_digits_plus_separator() {
+        let mut buffer = [0u8; 6];
+        let len = bytes_to_hex_slice(&[0x0a, 0xff], &mut buffer).unwrap();
+
+        assert_that!(len, eq 6);
+        assert_that!(&buffer[..len], eq b"0a ff ".as_slice());
+    }
+
+    #[test]
+    fn bytes_to_hex_slice_reports_buffer_too_small() {
+        let mut buffer = [0u8; 2];
+        let result = bytes_to_hex_slice(&[0x0a, 0xff], &mut buffer);
+
+        assert_that!(result.err(), eq Some(HexToBytesConversionError::BufferTooSmall));
+    }
+
+    #[test]
+    fn hex_string_to_slice_decodes_into_buffer() {
+        let mut buffer = [0u8; 4];
+        let len = hex_string_to_slice("de ad be ef", &mut buffer).unwrap();
+
+        assert_that!(len, eq 4);
+        assert_that!(&buffer[..len], eq [0xde, 0xad, 0xbe, 0xef].as_slice());
+    }
+
+    #[test]
+    fn hex_string_to_slice_reports_buffer_too_small() {
+        let mut buffer = [0u8; 1];
+        let result = hex_string_to_slice("de ad", &mut buffer);
+
+        assert_that!(result.err(), eq Some(HexToBytesConversionError::BufferTooSmall));
+    }
+
+    #[test]
+    fn hex_string_to_slice_rejects_invalid_hex_code() {
+        let mut buffer = [0u8; 4];
+        let result = hex_string_to_slice("zz", &mut buffer);
+
+        assert_that!(result.err(), eq Some(HexToBytesConversionError::InvalidHexCode));
+    }
+}
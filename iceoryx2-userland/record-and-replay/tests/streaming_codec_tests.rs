@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod streaming_codec {
+    use iceoryx2_pal_testing::assert_that;
+    use iceoryx2_userland_record_and_replay::streaming_codec::{
+        HexRecordReader, HexRecordWriter, SliceCursor, StreamStatus,
+    };
+
+    fn decode(input: &[u8]) -> Vec<u8> {
+        let mut reader = HexRecordReader::new();
+        let mut out = Vec::new();
+        let mut data = input.to_vec();
+        let mut cursor = SliceCursor::new(&mut data);
+        reader.read_from(&mut cursor, |b| out.push(b)).unwrap();
+        reader.finish(|b| out.push(b)).unwrap();
+        out
+    }
+
+    #[test]
+    fn writer_resumes_when_output_buffer_fills_mid_record() {
+        let record = [0xde, 0xad, 0xbe, 0xef];
+        let mut writer = HexRecordWriter::new(&record);
+
+        let mut encoded = Vec::new();
+        loop {
+            // Feed the writer a tiny buffer so it fills mid-record every step.
+            let mut chunk = [0u8; 3];
+            let mut cursor = SliceCursor::new(&mut chunk);
+            let status = writer.write_into(&mut cursor);
+            encoded.extend_from_slice(&chunk[..cursor.position()]);
+            if status == StreamStatus::Complete {
+                break;
+            }
+        }
+
+        assert_that!(encoded, eq b"de ad be ef ".to_vec());
+        assert_that!(writer.is_complete(), eq true);
+    }
+
+    #[test]
+    fn two_digit_tokens_round_trip() {
+        assert_that!(decode(b"de ad be ef "), eq vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn single_digit_tokens_are_not_merged_across_separator() {
+        assert_that!(decode(b"a b"), eq vec![0x0a, 0x0b]);
+    }
+
+    #[test]
+    fn token_split_across_cursors_resumes() {
+        let mut reader = HexRecordReader::new();
+        let mut out = Vec::new();
+
+        let mut first = b"d".to_vec();
+        let mut c1 = SliceCursor::new(&mut first);
+        let status = reader.read_from(&mut c1, |b| out.push(b)).unwrap();
+        assert_that!(status, eq StreamStatus::Incomplete);
+
+        let mut second = b"e ".to_vec();
+        let mut c2 = SliceCursor::new(&mut second);
+        reader.read_from(&mut c2, |b| out.push(b)).unwrap();
+        reader.finish(|b| out.push(b)).unwrap();
+
+        assert_that!(out, eq vec![0xde]);
+    }
+
+    #[test]
+    fn invalid_hex_code_is_rejected() {
+        let mut reader = HexRecordReader::new();
+        let mut data = b"zz ".to_vec();
+        let mut cursor = SliceCursor::new(&mut data);
+        let result = reader.read_from(&mut cursor, |_| {});
+        assert_that!(result.is_err(), eq true);
+    }
+}